@@ -13,8 +13,9 @@
 //! queue length of those unbounded channels.
 //!
 //! Note: While this should be reasonably performant, given that it boils down
-//! to a single atomic operation per send and receive, it is not meant to run in
-//! production.
+//! to two label-keyed lookups plus two atomic operations per send and receive
+//! (one for the event counter, one for the queue size gauge), it is not meant
+//! to run in production.
 //!
 //! Note: Keep in mind that this is using globally initialized counters. While
 //! not in any way a programming best practice, using global counters enables
@@ -22,10 +23,32 @@
 //! is no need to initialize counters and no need to register them with a
 //! registry in place.
 //!
+//! Channels are labeled via [`unbounded_labeled`] so that metrics for dozens
+//! of channels can be told apart instead of collapsing into a single global
+//! sum. [`unbounded`] is a thin wrapper around [`unbounded_labeled`] using
+//! `"unnamed"` as the label for callers that do not care to name their
+//! channel.
+//!
+//! Besides the monotonic event counters, a `instrumented_mpsc_channel_queue_size`
+//! gauge exposes the current in-flight queue length per channel, so one does
+//! not have to subtract two counters across scrapes (which is racy) to find
+//! out whether a channel is backing up.
+//!
+//! [`UnboundedSender`] is `Clone`, since multiple producers are the whole
+//! point of an mpsc channel. An `instrumented_mpsc_active_senders` gauge
+//! tracks how many producer handles are currently outstanding per channel,
+//! so the `dropped` accounting on the receiver stays meaningful even when
+//! only some of several senders disconnect.
+//!
+//! [`bounded`] brings the same visibility to
+//! [`futures::channel::mpsc::channel`], under its own `instrumented_mpsc_bounded_*`
+//! metric family, so one does not have to give up metrics in exchange for the
+//! backpressure a bounded channel provides.
+//!
 //! ```rust
 //! use futures::StreamExt;
 //! use instrumented_mpsc::{register_metrics, unbounded};
-//! use prometheus::{Counter, Encoder, Registry, TextEncoder};
+//! use prometheus::{Encoder, Registry, TextEncoder};
 //! let registry = Registry::new();
 //!
 //! register_metrics(&registry);
@@ -45,18 +68,18 @@
 //! let metric_families = registry.gather();
 //! encoder.encode(&metric_families, &mut buffer).unwrap();
 //!
-//! assert_eq!(String::from_utf8(buffer).unwrap(), "# HELP instrumented_mpsc_channels_created_total Channels created total.\
-//! \n# TYPE instrumented_mpsc_channels_created_total counter\
-//! \ninstrumented_mpsc_channels_created_total 1\
-//! \n# HELP instrumented_mpsc_channels_dropped_total Channels dropped total.\
-//! \n# TYPE instrumented_mpsc_channels_dropped_total counter\
-//! \ninstrumented_mpsc_channels_dropped_total 1\
-//! \n# HELP instrumented_mpsc_msgs_received_total Messages received total.\
-//! \n# TYPE instrumented_mpsc_msgs_received_total counter\
-//! \ninstrumented_mpsc_msgs_received_total 1\
-//! \n# HELP instrumented_mpsc_msgs_send_total Messages send total.\
-//! \n# TYPE instrumented_mpsc_msgs_send_total counter\
-//! \ninstrumented_mpsc_msgs_send_total 1\n");
+//! assert_eq!(String::from_utf8(buffer).unwrap(), "# HELP instrumented_mpsc_active_senders Number of active senders for a channel.\
+//! \n# TYPE instrumented_mpsc_active_senders gauge\
+//! \ninstrumented_mpsc_active_senders{entity=\"unnamed\"} 1\
+//! \n# HELP instrumented_mpsc_channel_events_total Channel events total.\
+//! \n# TYPE instrumented_mpsc_channel_events_total counter\
+//! \ninstrumented_mpsc_channel_events_total{action=\"created\",entity=\"unnamed\"} 1\
+//! \ninstrumented_mpsc_channel_events_total{action=\"dropped\",entity=\"unnamed\"} 1\
+//! \ninstrumented_mpsc_channel_events_total{action=\"received\",entity=\"unnamed\"} 1\
+//! \ninstrumented_mpsc_channel_events_total{action=\"send\",entity=\"unnamed\"} 1\
+//! \n# HELP instrumented_mpsc_channel_queue_size Current in-flight queue length of a channel.\
+//! \n# TYPE instrumented_mpsc_channel_queue_size gauge\
+//! \ninstrumented_mpsc_channel_queue_size{entity=\"unnamed\"} 0\n");
 //! ```
 
 #[macro_use]
@@ -64,93 +87,198 @@ extern crate lazy_static;
 
 use futures::{
     channel::mpsc::{self, SendError, TrySendError},
-    stream::Stream,
+    sink::Sink,
+    stream::{FusedStream, Stream},
 };
-use prometheus::{Counter, Registry};
+use prometheus::core::{AtomicF64, GenericCounterVec, GenericGaugeVec};
+use prometheus::{Opts, Registry};
 
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
 lazy_static! {
-    static ref CHANNELS_CREATED: Counter = Counter::new(
-        "instrumented_mpsc_channels_created_total",
-        "Channels created total.",
+    /// Counts channel lifecycle and traffic events, labeled by the channel's
+    /// `entity` name (see [`unbounded_labeled`]) and the `action` that
+    /// occurred: `created`, `dropped`, `send` or `received`.
+    static ref CHANNEL_EVENTS: GenericCounterVec<AtomicF64> = GenericCounterVec::new(
+        Opts::new(
+            "instrumented_mpsc_channel_events_total",
+            "Channel events total.",
+        ),
+        &["entity", "action"],
+    )
+    .unwrap();
+
+    /// Current in-flight queue length of a channel, labeled by the channel's
+    /// `entity` name. Incremented on a successful send, decremented once an
+    /// item is yielded to the receiver, and reset to zero when the receiver
+    /// is dropped.
+    ///
+    /// Because multiple senders and one receiver touch the same gauge
+    /// concurrently, the gauge can transiently read a slightly
+    /// negative-adjusted value around a scrape, but converges.
+    static ref CHANNEL_QUEUE_SIZE: GenericGaugeVec<AtomicF64> = GenericGaugeVec::new(
+        Opts::new(
+            "instrumented_mpsc_channel_queue_size",
+            "Current in-flight queue length of a channel.",
+        ),
+        &["entity"],
+    )
+    .unwrap();
+
+    /// Number of currently live [`UnboundedSender`] handles for a channel,
+    /// labeled by the channel's `entity` name. Incremented when a channel is
+    /// created or a sender is cloned, decremented when a sender is dropped,
+    /// so the `dropped` accounting on [`UnboundedReceiver`] stays meaningful
+    /// even when only some of several senders disconnect.
+    static ref ACTIVE_SENDERS: GenericGaugeVec<AtomicF64> = GenericGaugeVec::new(
+        Opts::new(
+            "instrumented_mpsc_active_senders",
+            "Number of active senders for a channel.",
+        ),
+        &["entity"],
+    )
+    .unwrap();
+
+    /// Same as [`CHANNEL_EVENTS`], but for [`bounded`] channels. Kept as its
+    /// own metric family so unbounded and bounded channels can be told apart
+    /// at a glance.
+    static ref BOUNDED_CHANNEL_EVENTS: GenericCounterVec<AtomicF64> = GenericCounterVec::new(
+        Opts::new(
+            "instrumented_mpsc_bounded_channel_events_total",
+            "Bounded channel events total.",
+        ),
+        &["entity", "action"],
     )
     .unwrap();
-    static ref CHANNELS_DROPPED: Counter = Counter::new(
-        "instrumented_mpsc_channels_dropped_total",
-        "Channels dropped total."
+
+    /// Same as [`CHANNEL_QUEUE_SIZE`], but for [`bounded`] channels.
+    static ref BOUNDED_CHANNEL_QUEUE_SIZE: GenericGaugeVec<AtomicF64> = GenericGaugeVec::new(
+        Opts::new(
+            "instrumented_mpsc_bounded_channel_queue_size",
+            "Current occupancy of a bounded channel.",
+        ),
+        &["entity"],
     )
     .unwrap();
-    static ref MSGS_SEND: Counter =
-        Counter::new("instrumented_mpsc_msgs_send_total", "Messages send total.",).unwrap();
-    static ref MSGS_RECEIVED: Counter = Counter::new(
-        "instrumented_mpsc_msgs_received_total",
-        "Messages received total."
+
+    /// Same as [`ACTIVE_SENDERS`], but for [`bounded`] channels.
+    static ref BOUNDED_ACTIVE_SENDERS: GenericGaugeVec<AtomicF64> = GenericGaugeVec::new(
+        Opts::new(
+            "instrumented_mpsc_bounded_active_senders",
+            "Number of active senders for a bounded channel.",
+        ),
+        &["entity"],
     )
     .unwrap();
 }
 
-/// Register metrics like `instrumented_mpsc_msgs_received_total` with the given
-/// registry.
+/// Register metrics like `instrumented_mpsc_channel_events_total` with the
+/// given registry.
 pub fn register_metrics(registry: &Registry) {
     registry
-        .register(Box::new(CHANNELS_CREATED.clone()))
+        .register(Box::new(CHANNEL_EVENTS.clone()))
         .unwrap();
 
     registry
-        .register(Box::new(CHANNELS_DROPPED.clone()))
+        .register(Box::new(CHANNEL_QUEUE_SIZE.clone()))
         .unwrap();
 
-    registry.register(Box::new(MSGS_SEND.clone())).unwrap();
+    registry.register(Box::new(ACTIVE_SENDERS.clone())).unwrap();
 
-    registry.register(Box::new(MSGS_RECEIVED.clone())).unwrap();
+    registry
+        .register(Box::new(BOUNDED_CHANNEL_EVENTS.clone()))
+        .unwrap();
+
+    registry
+        .register(Box::new(BOUNDED_CHANNEL_QUEUE_SIZE.clone()))
+        .unwrap();
+
+    registry
+        .register(Box::new(BOUNDED_ACTIVE_SENDERS.clone()))
+        .unwrap();
 }
 
 /// Wraps [`futures::channel::mpsc::unbounded`] returning an
 /// [`futures::channel::mpsc::UnboundedSender`]
 /// [`futures::channel::mpsc::UnboundedReceiver`] set with small wrappers
 /// counting messages send and received.
-//
-// TODO: Allow list of labels to be passed here.
+///
+/// Thin wrapper around [`unbounded_labeled`] using `"unnamed"` as the
+/// channel's label. Prefer [`unbounded_labeled`] when running more than a
+/// handful of channels, so their metrics can be told apart.
 pub fn unbounded<T>() -> (UnboundedSender<T>, UnboundedReceiver<T>) {
-    CHANNELS_CREATED.inc();
+    unbounded_labeled("unnamed")
+}
+
+/// Same as [`unbounded`], but labels the channel's metrics with `name`
+/// instead of defaulting to `"unnamed"`.
+///
+/// `name` becomes the `entity` label on
+/// `instrumented_mpsc_channel_events_total`, letting a user with dozens of
+/// unbounded channels tell which one is backing up instead of seeing one
+/// global sum.
+pub fn unbounded_labeled<T>(name: &'static str) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
+    CHANNEL_EVENTS.with_label_values(&[name, "created"]).inc();
+    ACTIVE_SENDERS.with_label_values(&[name]).inc();
     let (tx, rx) = mpsc::unbounded();
-    (UnboundedSender(tx), UnboundedReceiver(rx))
+    (
+        UnboundedSender { inner: tx, name },
+        UnboundedReceiver { inner: rx, name },
+    )
 }
 
 /// Wraps [`futures::channel::mpsc::UnboundedSender`] counting messages send.
-pub struct UnboundedSender<T>(mpsc::UnboundedSender<T>);
+///
+/// Implements [`Sink`] and [`Clone`], just like the wrapped sender, so it can
+/// be used with combinators such as `forward`/`send_all` and cloned to create
+/// multiple producers.
+pub struct UnboundedSender<T> {
+    inner: mpsc::UnboundedSender<T>,
+    name: &'static str,
+}
 
 impl<T> UnboundedSender<T> {
     /// Check if the channel is ready to receive a message.
     pub fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
-        self.0.poll_ready(ctx)
+        self.inner.poll_ready(ctx)
     }
 
     /// Returns whether this channel is closed without needing a context.
     pub fn is_closed(&self) -> bool {
-        self.0.is_closed()
+        self.inner.is_closed()
     }
 
     /// Closes this channel from the sender side, preventing any new messages.
     pub fn close_channel(&self) {
-        self.0.close_channel()
+        self.inner.close_channel()
     }
 
     /// Disconnects this sender from the channel, closing it if there are no more senders left.
     pub fn disconnect(&mut self) {
-        self.0.disconnect()
+        self.inner.disconnect()
     }
 
     pub fn start_send(&mut self, msg: T) -> Result<(), SendError> {
-        MSGS_SEND.inc();
-        self.0.start_send(msg)
+        CHANNEL_EVENTS
+            .with_label_values(&[self.name, "send"])
+            .inc();
+        let result = self.inner.start_send(msg);
+        if result.is_ok() {
+            CHANNEL_QUEUE_SIZE.with_label_values(&[self.name]).inc();
+        }
+        result
     }
 
     pub fn unbounded_send(&self, msg: T) -> Result<(), TrySendError<T>> {
-        MSGS_SEND.inc();
-        self.0.unbounded_send(msg)
+        CHANNEL_EVENTS
+            .with_label_values(&[self.name, "send"])
+            .inc();
+        let result = self.inner.unbounded_send(msg);
+        if result.is_ok() {
+            CHANNEL_QUEUE_SIZE.with_label_values(&[self.name]).inc();
+        }
+        result
     }
 
     // TODO: needs access to inner sender. Maybe as_ref?
@@ -159,9 +287,62 @@ impl<T> UnboundedSender<T> {
     // }}
 }
 
+impl<T> Clone for UnboundedSender<T> {
+    /// Clones this sender, creating an additional producer handle on the same
+    /// channel. Bumps [`ACTIVE_SENDERS`] so it keeps tracking how many
+    /// producer handles are outstanding.
+    fn clone(&self) -> Self {
+        ACTIVE_SENDERS.with_label_values(&[self.name]).inc();
+        UnboundedSender {
+            inner: self.inner.clone(),
+            name: self.name,
+        }
+    }
+}
+
+impl<T> Drop for UnboundedSender<T> {
+    fn drop(&mut self) {
+        ACTIVE_SENDERS.with_label_values(&[self.name]).dec();
+    }
+}
+
+impl<T> Sink<T> for UnboundedSender<T> {
+    type Error = SendError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        CHANNEL_EVENTS
+            .with_label_values(&[this.name, "send"])
+            .inc();
+        let result = Pin::new(&mut this.inner).start_send(msg);
+        if result.is_ok() {
+            CHANNEL_QUEUE_SIZE.with_label_values(&[this.name]).inc();
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
 /// Wraps [`futures::channel::mpsc::UnboundedReceiver`] counting messages
 /// received.
-pub struct UnboundedReceiver<T>(mpsc::UnboundedReceiver<T>);
+///
+/// Implements [`FusedStream`], just like the wrapped receiver, so it can be
+/// dropped into `futures::select!` loops in place of the raw receiver.
+pub struct UnboundedReceiver<T> {
+    inner: mpsc::UnboundedReceiver<T>,
+    name: &'static str,
+}
 
 impl<T> Unpin for UnboundedReceiver<T> {}
 
@@ -169,19 +350,214 @@ impl<T> Stream for UnboundedReceiver<T> {
     type Item = T;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
-        match <mpsc::UnboundedReceiver<T> as Stream>::poll_next(Pin::new(&mut self.0), cx) {
+        match <mpsc::UnboundedReceiver<T> as Stream>::poll_next(Pin::new(&mut self.inner), cx) {
             Poll::Ready(Some(item)) => {
-                MSGS_RECEIVED.inc();
+                CHANNEL_EVENTS
+                    .with_label_values(&[self.name, "received"])
+                    .inc();
+                CHANNEL_QUEUE_SIZE.with_label_values(&[self.name]).dec();
                 Poll::Ready(Some(item))
             }
-            x @ _ => x,
+            x => x,
         }
     }
 }
 
 impl<T> Drop for UnboundedReceiver<T> {
     fn drop(&mut self) {
-        CHANNELS_DROPPED.inc();
+        CHANNEL_EVENTS
+            .with_label_values(&[self.name, "dropped"])
+            .inc();
+        // A drained-then-dropped channel should not leave a phantom backlog
+        // behind for its entity.
+        CHANNEL_QUEUE_SIZE.with_label_values(&[self.name]).set(0_f64);
+    }
+}
+
+impl<T> UnboundedReceiver<T> {
+    /// Returns whether this receiver has already yielded `None`, i.e. a poll
+    /// observed the channel closed and drained, and thus will never yield
+    /// another item. A closed channel whose last item was received but that
+    /// has not yet been polled again still reports `false`.
+    pub fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<T> FusedStream for UnboundedReceiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+/// Wraps [`futures::channel::mpsc::channel`] returning a [`Sender`]
+/// [`Receiver`] set with small wrappers counting messages send and received,
+/// the same way [`unbounded`] does for unbounded channels.
+///
+/// Thin wrapper around [`bounded_labeled`] using `"unnamed"` as the channel's
+/// label.
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    bounded_labeled(capacity, "unnamed")
+}
+
+/// Same as [`bounded`], but labels the channel's metrics with `name` instead
+/// of defaulting to `"unnamed"`.
+pub fn bounded_labeled<T>(capacity: usize, name: &'static str) -> (Sender<T>, Receiver<T>) {
+    BOUNDED_CHANNEL_EVENTS
+        .with_label_values(&[name, "created"])
+        .inc();
+    BOUNDED_ACTIVE_SENDERS.with_label_values(&[name]).inc();
+    let (tx, rx) = mpsc::channel(capacity);
+    (
+        Sender { inner: tx, name },
+        Receiver { inner: rx, name },
+    )
+}
+
+/// Wraps [`futures::channel::mpsc::Sender`] counting messages send.
+///
+/// Implements [`Sink`] and [`Clone`], just like the wrapped sender, so it can
+/// be used with combinators such as `forward`/`send_all` and cloned to create
+/// multiple producers.
+pub struct Sender<T> {
+    inner: mpsc::Sender<T>,
+    name: &'static str,
+}
+
+impl<T> Sender<T> {
+    /// Check if the channel is ready to receive a message.
+    pub fn poll_ready(&mut self, ctx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        self.inner.poll_ready(ctx)
+    }
+
+    /// Returns whether this channel is closed without needing a context.
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    /// Closes this channel from the sender side, preventing any new messages.
+    pub fn close_channel(&mut self) {
+        self.inner.close_channel()
+    }
+
+    /// Disconnects this sender from the channel, closing it if there are no more senders left.
+    pub fn disconnect(&mut self) {
+        self.inner.disconnect()
+    }
+
+    pub fn start_send(&mut self, msg: T) -> Result<(), SendError> {
+        BOUNDED_CHANNEL_EVENTS
+            .with_label_values(&[self.name, "send"])
+            .inc();
+        let result = self.inner.start_send(msg);
+        if result.is_ok() {
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&[self.name])
+                .inc();
+        }
+        result
+    }
+
+    pub fn try_send(&mut self, msg: T) -> Result<(), TrySendError<T>> {
+        BOUNDED_CHANNEL_EVENTS
+            .with_label_values(&[self.name, "send"])
+            .inc();
+        let result = self.inner.try_send(msg);
+        if result.is_ok() {
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&[self.name])
+                .inc();
+        }
+        result
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    /// Clones this sender, creating an additional producer handle on the same
+    /// channel. Bumps [`BOUNDED_ACTIVE_SENDERS`], mirroring
+    /// [`UnboundedSender`]'s `Clone` impl.
+    fn clone(&self) -> Self {
+        BOUNDED_ACTIVE_SENDERS.with_label_values(&[self.name]).inc();
+        Sender {
+            inner: self.inner.clone(),
+            name: self.name,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        BOUNDED_ACTIVE_SENDERS.with_label_values(&[self.name]).dec();
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = SendError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().inner.poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        BOUNDED_CHANNEL_EVENTS
+            .with_label_values(&[this.name, "send"])
+            .inc();
+        let result = Pin::new(&mut this.inner).start_send(msg);
+        if result.is_ok() {
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&[this.name])
+                .inc();
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+/// Wraps [`futures::channel::mpsc::Receiver`] counting messages received.
+pub struct Receiver<T> {
+    inner: mpsc::Receiver<T>,
+    name: &'static str,
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match <mpsc::Receiver<T> as Stream>::poll_next(Pin::new(&mut self.inner), cx) {
+            Poll::Ready(Some(item)) => {
+                BOUNDED_CHANNEL_EVENTS
+                    .with_label_values(&[self.name, "received"])
+                    .inc();
+                BOUNDED_CHANNEL_QUEUE_SIZE
+                    .with_label_values(&[self.name])
+                    .dec();
+                Poll::Ready(Some(item))
+            }
+            x => x,
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        BOUNDED_CHANNEL_EVENTS
+            .with_label_values(&[self.name, "dropped"])
+            .inc();
+        // A drained-then-dropped channel should not leave a phantom backlog
+        // behind for its entity.
+        BOUNDED_CHANNEL_QUEUE_SIZE
+            .with_label_values(&[self.name])
+            .set(0_f64);
     }
 }
 
@@ -205,9 +581,186 @@ mod tests {
 
         drop(rx);
 
-        assert_eq!(4, registry.gather().len());
-        for metric in registry.gather() {
-            assert_eq!(1_f64, metric.get_metric()[0].get_counter().get_value());
+        let metric_families = registry.gather();
+        let events = metric_families
+            .iter()
+            .find(|f| f.get_name() == "instrumented_mpsc_channel_events_total")
+            .unwrap();
+        // CHANNEL_EVENTS is a process-global vec shared with every other
+        // test in this module, so only assert on the "unnamed" entity this
+        // test actually touches instead of looping over every label set.
+        for metric in events.get_metric() {
+            let is_unnamed = metric
+                .get_label()
+                .iter()
+                .any(|l| l.get_name() == "entity" && l.get_value() == "unnamed");
+            if is_unnamed {
+                assert_eq!(1_f64, metric.get_counter().get_value());
+            }
         }
     }
+
+    #[test]
+    fn labeled_channels_are_distinguishable() {
+        let registry = Registry::new();
+        register_metrics(&registry);
+
+        let (tx_a, _rx_a) = unbounded_labeled::<()>("a");
+        let (tx_b, _rx_b) = unbounded_labeled::<()>("b");
+
+        tx_a.unbounded_send(()).unwrap();
+        tx_a.unbounded_send(()).unwrap();
+        tx_b.unbounded_send(()).unwrap();
+
+        let metric_families = registry.gather();
+        let metrics = metric_families
+            .iter()
+            .find(|f| f.get_name() == "instrumented_mpsc_channel_events_total")
+            .unwrap()
+            .get_metric();
+
+        let sent_for = |entity: &str| {
+            metrics
+                .iter()
+                .find(|m| {
+                    m.get_label()
+                        .iter()
+                        .any(|l| l.get_name() == "entity" && l.get_value() == entity)
+                        && m.get_label()
+                            .iter()
+                            .any(|l| l.get_name() == "action" && l.get_value() == "send")
+                })
+                .unwrap()
+                .get_counter()
+                .get_value()
+        };
+
+        assert_eq!(2_f64, sent_for("a"));
+        assert_eq!(1_f64, sent_for("b"));
+    }
+
+    #[test]
+    fn queue_size_tracks_in_flight_messages_and_resets_on_drop() {
+        let (tx, mut rx) = unbounded_labeled::<()>("queue-size-test");
+
+        tx.unbounded_send(()).unwrap();
+        tx.unbounded_send(()).unwrap();
+        assert_eq!(
+            2_f64,
+            CHANNEL_QUEUE_SIZE
+                .with_label_values(&["queue-size-test"])
+                .get()
+        );
+
+        futures::executor::block_on(async {
+            rx.next().await.unwrap();
+        });
+        assert_eq!(
+            1_f64,
+            CHANNEL_QUEUE_SIZE
+                .with_label_values(&["queue-size-test"])
+                .get()
+        );
+
+        drop(rx);
+        assert_eq!(
+            0_f64,
+            CHANNEL_QUEUE_SIZE
+                .with_label_values(&["queue-size-test"])
+                .get()
+        );
+    }
+
+    #[test]
+    fn sender_is_cloneable_and_usable_as_a_sink() {
+        use futures::SinkExt;
+
+        let (tx, mut rx) = unbounded_labeled("clone-sink-test");
+        let mut tx_clone = tx.clone();
+
+        futures::executor::block_on(async {
+            tx_clone.send(1).await.unwrap();
+            drop(tx_clone);
+            drop(tx);
+            assert_eq!(Some(1), rx.next().await);
+            assert_eq!(None, rx.next().await);
+        });
+    }
+
+    #[test]
+    fn unbounded_receiver_is_terminated_once_drained_and_closed() {
+        let (tx, mut rx) = unbounded_labeled::<()>("fused-test");
+
+        tx.unbounded_send(()).unwrap();
+        assert!(!rx.is_terminated());
+
+        tx.close_channel();
+        futures::executor::block_on(async {
+            assert_eq!(Some(()), rx.next().await);
+        });
+        assert!(!rx.is_terminated());
+
+        futures::executor::block_on(async {
+            assert_eq!(None, rx.next().await);
+        });
+        assert!(rx.is_terminated());
+    }
+
+    #[test]
+    fn bounded_channel_tracks_queue_size_and_resets_on_drop() {
+        let (mut tx, mut rx) = bounded_labeled::<()>(2, "bounded-test");
+
+        tx.try_send(()).unwrap();
+        tx.try_send(()).unwrap();
+        assert_eq!(
+            2_f64,
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&["bounded-test"])
+                .get()
+        );
+
+        futures::executor::block_on(async {
+            rx.next().await.unwrap();
+        });
+        assert_eq!(
+            1_f64,
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&["bounded-test"])
+                .get()
+        );
+
+        drop(rx);
+        assert_eq!(
+            0_f64,
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&["bounded-test"])
+                .get()
+        );
+    }
+
+    #[test]
+    fn bounded_channel_does_not_count_a_rejected_send_toward_queue_size() {
+        let (mut tx, _rx) = bounded_labeled::<()>(0, "bounded-full-test");
+
+        // `futures::channel::mpsc::channel` grants each live sender one
+        // guaranteed slot on top of `capacity`, so with a single sender the
+        // first `try_send` into a `capacity: 0` channel still succeeds and
+        // bumps the gauge. Only the second, past that slot, is rejected with
+        // `TrySendError::Full` and must not bump it further.
+        tx.try_send(()).unwrap();
+        assert_eq!(
+            1_f64,
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&["bounded-full-test"])
+                .get()
+        );
+
+        assert!(tx.try_send(()).unwrap_err().is_full());
+        assert_eq!(
+            1_f64,
+            BOUNDED_CHANNEL_QUEUE_SIZE
+                .with_label_values(&["bounded-full-test"])
+                .get()
+        );
+    }
 }